@@ -0,0 +1,70 @@
+// Conversion Traits: From, Into, TryFrom, and TryInto
+// The error-handling example already used `From<std::num::ParseIntError> for MyError`
+// to convert one error type into another. `From` is part of a broader family of four
+// conversion traits, and this chapter makes the relationships between them explicit.
+
+// The `Point` struct will be the target of our conversions.
+#[derive(Debug)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+// Implementing `From` for Infallible Conversions
+// `From<(i32, i32)> for Point` says "a `(i32, i32)` tuple can always be turned
+// into a `Point`". This also gives us `Point::from((1, 2))` for free.
+impl From<(i32, i32)> for Point {
+    fn from(pair: (i32, i32)) -> Self {
+        Point { x: pair.0, y: pair.1 }
+    }
+}
+
+// Getting `Into` for Free
+// The standard library provides a blanket implementation:
+// `impl<T, U> Into<U> for T where U: From<T>`.
+// Because we implemented `From<(i32, i32)> for Point`, the tuple automatically
+// gains `Into<Point>` as well, so `(1, 2).into()` works without any extra code.
+
+// Implementing `TryFrom` for Fallible Conversions
+// Not every conversion can succeed. `TryFrom<(i64, i64)> for Point` mirrors
+// `From`, but returns a `Result` so it can report a failure when a coordinate
+// doesn't fit into an `i32`.
+impl std::convert::TryFrom<(i64, i64)> for Point {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(pair: (i64, i64)) -> Result<Self, Self::Error> {
+        let x = i32::try_from(pair.0)?;
+        let y = i32::try_from(pair.1)?;
+        Ok(Point { x, y })
+    }
+}
+
+// Just like `From`/`Into`, implementing `TryFrom` gives us `TryInto` for free
+// via the standard library's blanket implementation. A function that builds a
+// `Point` from a pair of `i64`s can simply propagate the conversion error.
+fn point_from_i64_pair(pair: (i64, i64)) -> Result<Point, std::num::TryFromIntError> {
+    use std::convert::TryInto;
+    pair.try_into()
+}
+
+fn main() {
+    // Using `From` directly.
+    let p1 = Point::from((1, 2));
+    println!("p1 = {:?} (x = {}, y = {})", p1, p1.x, p1.y);
+
+    // Using `Into`, which is available automatically because `Point` implements `From`.
+    let p2: Point = (3, 4).into();
+    println!("p2 = {:?}", p2);
+
+    // `TryFrom` succeeds when both coordinates fit in an `i32`.
+    match point_from_i64_pair((5, 6)) {
+        Ok(point) => println!("p3 = {:?}", point),
+        Err(error) => println!("Error: {}", error),
+    }
+
+    // `TryFrom` fails when a coordinate is too large for an `i32`.
+    match point_from_i64_pair((i64::MAX, 6)) {
+        Ok(point) => println!("p4 = {:?}", point),
+        Err(error) => println!("Error: {}", error),
+    }
+}
@@ -15,10 +15,10 @@ fn divide(a: i32, b: i32) -> Result<i32, String> {
     }
 }
 
-// In the `main` function, the `divide` function is called with two different arguments.
+// The `divide` function is called with two different arguments.
 // The `match` expression is used to handle the `Result` returned by the `divide` function.
 // If the `Result` is `Ok`, the result is printed. If the `Result` is `Err`, the error message is printed.
-fn main() {
+fn simple_match_example() {
     match divide(10, 2) {
         Ok(result) => println!("Result: {}", result),
         Err(error) => println!("Error: {}", error),
@@ -42,10 +42,10 @@ fn parse_and_double(s: &str) -> Result<i32, std::num::ParseIntError> {
     Ok(num * 2)
 }
 
-// In the `main` function, the `parse_and_double` function is called with two different arguments.
+// The `parse_and_double` function is called with two different arguments.
 // The `match` expression is used to handle the `Result` returned by the `parse_and_double` function.
 // If the `Result` is `Ok`, the result is printed. If the `Result` is `Err`, the error message is printed.
-fn main() {
+fn question_mark_example() {
     let result = parse_and_double("42");
     match result {
         Ok(value) => println!("Result: {}", value),
@@ -93,17 +93,17 @@ fn parse_and_double_custom_err(s: &str) -> Result<i32, MyError> {
 
 
 
-// In the `main` function, the `parse_and_double` function is called with two different arguments.
-// The `match` expression is used to handle the `Result` returned by the `parse_and_double` function.
+// The `parse_and_double_custom_err` function is called with two different arguments.
+// The `match` expression is used to handle the `Result` returned by the function.
 // The different error cases are handled separately, with specific actions for `DivisionByZero` and `ParseError`.
-fn main() {
-    match parse_and_double("42") {
+fn custom_error_example() {
+    match parse_and_double_custom_err("42") {
         Ok(result) => println!("Result: {}", result),
         Err(MyError::DivisionByZero) => println!("Error: Division by zero"),
         Err(MyError::ParseError(error)) => println!("Error: {}", error),
     }
 
-    match parse_and_double("not_a_number") {
+    match parse_and_double_custom_err("not_a_number") {
         Ok(result) => println!("Result: {}", result),
         Err(MyError::DivisionByZero) => println!("Error: Division by zero"),
         Err(MyError::ParseError(error)) => println!("Error: {}", error),
@@ -112,18 +112,97 @@ fn main() {
 
 
 
+// Implementing `Display` and `std::error::Error` for Custom Errors
+// Deriving `Debug` is enough to print an error with `{:?}`, but idiomatic Rust
+// errors also implement `std::fmt::Display` (for a user-facing `{}` message)
+// and `std::error::Error` (which lets the type plug into the wider error
+// ecosystem, including `Box<dyn Error>`).
+
+// `Display` provides the human-readable message for each variant.
+// `DivisionByZero` gets a fixed message, while `ParseError` delegates to the
+// inner `ParseIntError`'s own `Display` implementation.
+impl std::fmt::Display for MyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MyError::DivisionByZero => write!(f, "cannot divide by zero"),
+            MyError::ParseError(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+// `std::error::Error` requires `Display` (and `Debug`) to already be implemented.
+// Overriding `source()` lets callers walk the chain of underlying errors:
+// `ParseError` exposes the `ParseIntError` that caused it, while
+// `DivisionByZero` has no underlying cause.
+impl std::error::Error for MyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MyError::ParseError(e) => Some(e),
+            MyError::DivisionByZero => None,
+        }
+    }
+}
+
+// Combining Heterogeneous Errors with `Box<dyn Error>`
+// Any type that implements `std::error::Error` automatically converts into
+// `Box<dyn std::error::Error>`, so a single `?` can propagate errors from
+// different sources. The standard library also converts a plain `String`
+// into the same boxed trait object.
+// Here, `s.parse()` can fail with `ParseIntError` and `divide` can fail with
+// a `String` message; both erase into the same boxed trait object.
+fn parse_and_divide(s: &str, divisor: i32) -> Result<i32, Box<dyn std::error::Error>> {
+    let num: i32 = s.parse()?;
+    let result = divide(num, divisor)?;
+    Ok(result)
+}
+
+// `parse_and_divide` is called with a valid number and a non-zero divisor,
+// then with an invalid number, then with a zero divisor. `Box<dyn Error>`
+// implements `Display`, so the error can be printed uniformly regardless of
+// which underlying error type produced it.
+fn box_dyn_error_example() {
+    match parse_and_divide("10", 2) {
+        Ok(result) => println!("Result: {}", result),
+        Err(error) => println!("Error: {}", error),
+    }
+
+    match parse_and_divide("not_a_number", 2) {
+        Ok(result) => println!("Result: {}", result),
+        Err(error) => println!("Error: {}", error),
+    }
+
+    match parse_and_divide("10", 0) {
+        Ok(result) => println!("Result: {}", result),
+        Err(error) => println!("Error: {}", error),
+    }
+}
+
+
+
 // Using `unwrap` and `expect`
 // The `unwrap` and `expect` methods can be used to handle `Result` values in a more concise way.
 // They provide a convenient way to handle the success case and panic with a custom error message in case of an error.
-// 
-// In the `main` function, the `parse_and_double` function is called with two different arguments.
+//
+// The `parse_and_double` function is called with two different arguments.
 // The `unwrap` method is used to handle the `Result` returned by the `parse_and_double` function.
 // If the `Result` is `Err`, the program will panic with a default error message.
 // The `expect` method is also used to handle the `Result`, and it allows you to provide a custom error message in case of a panic.
-fn main() {
+fn unwrap_expect_example() {
     let result = parse_and_double("42").unwrap();
     println!("Result: {}", result);
 
-    let result = parse_and_double("not_a_number").expect("Failed to parse number");
+    let result = parse_and_double("21").expect("Failed to parse number");
     println!("Result: {}", result);
+
+    // Calling `.expect("Failed to parse number")` on an `Err`, e.g.
+    // `parse_and_double("not_a_number")`, would panic with that message
+    // instead of returning control to the caller.
+}
+
+fn main() {
+    simple_match_example();
+    question_mark_example();
+    custom_error_example();
+    box_dyn_error_example();
+    unwrap_expect_example();
 }
\ No newline at end of file
@@ -0,0 +1,65 @@
+// Generics
+// `print_animal_info<T: Animal>` in the traits chapter showed the simplest form of
+// generics: one type parameter with a single trait bound. Generics go further than
+// that, supporting generic structs and multiple bounds combined with a `where` clause.
+
+// A Generic Struct
+// `Pair<T>` can hold two values of any single type `T`. The struct definition itself
+// has no bounds on `T`, so `Pair` can be built from any type at all.
+struct Pair<T> {
+    first: T,
+    second: T,
+}
+
+impl<T> Pair<T> {
+    fn new(first: T, second: T) -> Pair<T> {
+        Pair { first, second }
+    }
+}
+
+// Multiple Trait Bounds and `where` Clauses
+// This `impl` block only applies when `T` implements both `Display` (so the value
+// can be printed) and `PartialOrd` (so two values can be compared). Writing the
+// bounds with a `where` clause keeps the `impl` line readable once there is more
+// than one constraint.
+impl<T> Pair<T>
+where
+    T: std::fmt::Display + PartialOrd,
+{
+    fn cmp_display(&self) {
+        if self.first >= self.second {
+            println!("The largest member is {}", self.first);
+        } else {
+            println!("The largest member is {}", self.second);
+        }
+    }
+}
+
+// A Generic Function with Multiple Bounds
+// `largest` is constrained by two traits: `PartialOrd` so the items can be compared,
+// and `Copy` so items can be returned by value instead of by reference.
+fn largest<T: PartialOrd + Copy>(list: &[T]) -> T {
+    let mut largest = list[0];
+
+    for &item in list {
+        if item > largest {
+            largest = item;
+        }
+    }
+
+    largest
+}
+
+fn main() {
+    let pair = Pair::new(5, 10);
+    pair.cmp_display();
+
+    let words = Pair::new("apple", "banana");
+    words.cmp_display();
+
+    let numbers = vec![34, 50, 25, 100, 65];
+    println!("The largest number is {}", largest(&numbers));
+
+    let chars = vec!['y', 'm', 'a', 'q'];
+    println!("The largest char is {}", largest(&chars));
+}
@@ -0,0 +1,69 @@
+// The Iterator Trait
+// The `Iterator` trait is what powers Rust's `for` loops, `collect`, and all of the
+// adapter methods like `map` and `filter`. Unlike the `Animal`/`Vehicle` traits, which
+// only declare plain methods, `Iterator` also declares an associated type: `Item`.
+// Implementing types must say what `Item` is, and then provide a single required
+// method, `next`, that produces the next `Item` (or `None` when the sequence is done).
+
+// The `Counter` struct counts upward from zero until it reaches `max`.
+struct Counter {
+    count: u32,
+    max: u32,
+}
+
+impl Counter {
+    fn new(max: u32) -> Counter {
+        Counter { count: 0, max }
+    }
+}
+
+// Implementing `Iterator` for `Counter`.
+// `type Item = u32;` tells the compiler what kind of value `next` produces.
+// `next` is the only method we have to write by hand; every other method on
+// `Iterator` (`map`, `filter`, `take`, `sum`, `zip`, and many more) is a default
+// method that comes for free once `next` is implemented.
+impl Iterator for Counter {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.count < self.max {
+            self.count += 1;
+            Some(self.count)
+        } else {
+            None
+        }
+    }
+}
+
+fn main() {
+    // Consuming the iterator with a `for` loop.
+    // The `for` loop calls `next` repeatedly until it gets `None`.
+    for num in Counter::new(5) {
+        println!("for loop: {}", num);
+    }
+
+    // Consuming the iterator with `collect`, which gathers every produced
+    // `Item` into a new collection.
+    let collected: Vec<u32> = Counter::new(5).collect();
+    println!("collected: {:?}", collected);
+
+    // Because `next` is implemented, the default adapter methods are available
+    // for free. `map` transforms each item, `filter` keeps only matching items,
+    // and `take` limits how many items are pulled.
+    let doubled: Vec<u32> = Counter::new(5).map(|n| n * 2).collect();
+    println!("doubled: {:?}", doubled);
+
+    let even: Vec<u32> = Counter::new(5).filter(|n| n % 2 == 0).collect();
+    println!("even: {:?}", even);
+
+    let first_three: Vec<u32> = Counter::new(5).take(3).collect();
+    println!("first three: {:?}", first_three);
+
+    // `sum` consumes the iterator and adds up every item.
+    let total: u32 = Counter::new(5).sum();
+    println!("total: {}", total);
+
+    // `zip` pairs up items from two iterators, stopping when the shorter one runs out.
+    let zipped: Vec<(u32, u32)> = Counter::new(5).zip(Counter::new(5).skip(1)).collect();
+    println!("zipped: {:?}", zipped);
+}
@@ -0,0 +1,86 @@
+// Operator Overloading with std::ops
+// The `Animal` and `Vehicle` traits showed that traits can define shared behavior,
+// but that idea has a very concrete payoff: the built-in operators (`+`, `-`, `*`, ...)
+// are themselves just traits from `std::ops`. Implementing one of them for a type
+// is what lets you write `v1 + v2` instead of calling a method by name.
+
+// `PartialEq` is derived so that two `Vector2D`s can be compared with `==`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Vector2D {
+    x: f64,
+    y: f64,
+}
+
+// Implementing `Add`
+// `std::ops::Add` requires an associated `Output` type, which is what the `+`
+// expression evaluates to. Here, adding two vectors produces another vector.
+impl std::ops::Add for Vector2D {
+    type Output = Vector2D;
+
+    fn add(self, other: Vector2D) -> Vector2D {
+        Vector2D {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+// Implementing `Sub` follows the same pattern as `Add`.
+impl std::ops::Sub for Vector2D {
+    type Output = Vector2D;
+
+    fn sub(self, other: Vector2D) -> Vector2D {
+        Vector2D {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+// Implementing `Mul` for Vector * Vector (component-wise multiplication).
+impl std::ops::Mul for Vector2D {
+    type Output = Vector2D;
+
+    fn mul(self, other: Vector2D) -> Vector2D {
+        Vector2D {
+            x: self.x * other.x,
+            y: self.y * other.y,
+        }
+    }
+}
+
+// `Mul` is generic over its right-hand-side type (`Mul<Rhs>`), so the same trait
+// can be implemented again for a different `Rhs` to support scalar multiplication.
+// This lets `v * 2.0` and `v * v` both compile, dispatching to the matching impl.
+impl std::ops::Mul<f64> for Vector2D {
+    type Output = Vector2D;
+
+    fn mul(self, scalar: f64) -> Vector2D {
+        Vector2D {
+            x: self.x * scalar,
+            y: self.y * scalar,
+        }
+    }
+}
+
+fn main() {
+    let v1 = Vector2D { x: 1.0, y: 2.0 };
+    let v2 = Vector2D { x: 3.0, y: 4.0 };
+
+    // `+` dispatches to our `Add` implementation.
+    println!("v1 + v2 = {:?}", v1 + v2);
+
+    // `-` dispatches to our `Sub` implementation.
+    println!("v1 - v2 = {:?}", v1 - v2);
+
+    // `*` between two vectors dispatches to `Mul<Vector2D>`.
+    println!("v1 * v2 = {:?}", v1 * v2);
+
+    // `*` with a scalar dispatches to the separate `Mul<f64>` implementation.
+    println!("v1 * 2.0 = {:?}", v1 * 2.0);
+
+    // The derived `PartialEq` lets us compare vectors with `==`.
+    let v1_copy = v1;
+    println!("v1 == v1_copy: {}", v1 == v1_copy);
+    println!("v1 == v2: {}", v1 == v2);
+}
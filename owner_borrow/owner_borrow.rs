@@ -60,6 +60,68 @@ fn lifetimes() {
     println!("x = {}, y = {}", x, y);
 }
 
+// Lifetimes in Structs
+// The example above never needed an explicit lifetime annotation because `y`
+// never outlived `x`. Lifetimes become unavoidable once a struct needs to
+// store a borrowed field: the compiler has to know that the struct can't
+// outlive the data it borrows. `Excerpt<'a>` holds a string slice borrowed
+// from somewhere else, so it carries the lifetime `'a` of that borrow.
+struct Excerpt<'a> {
+    part: &'a str,
+}
+
+impl<'a> Excerpt<'a> {
+    // Methods can elide the lifetime: the compiler infers that a `&self`
+    // parameter's lifetime is what the return value borrows from, so `part`
+    // doesn't need to be written out as `fn part<'b>(&'b self) -> &'b str`.
+    fn part(&self) -> &str {
+        self.part
+    }
+}
+
+// Returning references from a function has the same requirement. `longest`
+// takes two borrowed strings and returns one of them, so the return type's
+// lifetime must be tied to both inputs. The single `'a` here says "the
+// returned reference is valid for as long as both `x` and `y` are valid".
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() > y.len() {
+        x
+    } else {
+        y
+    }
+}
+
+// A lifetime annotation never lets a function return a reference to one of
+// its own locals: a function like
+//
+//     fn invalid_longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+//         let result = String::from("temporary");
+//         result.as_str() // Error: cannot return a reference to local `result`
+//     }
+//
+// fails to compile, because `result` is dropped at the end of the function
+// while the returned reference would need to outlive it.
+
+// `'static` is a special lifetime meaning "valid for the entire duration of
+// the program". String literals are `&'static str` because they are baked
+// directly into the compiled binary.
+fn static_lifetime() -> &'static str {
+    "this string lives for the whole program"
+}
+
+fn advanced_lifetimes() {
+    let novel = String::from("Call me Ishmael. Some years ago...");
+    let first_sentence = novel.split('.').next().expect("no sentence found");
+    let excerpt = Excerpt { part: first_sentence };
+    println!("excerpt: {}", excerpt.part());
+
+    let string1 = String::from("long string is long");
+    let string2 = String::from("short");
+    println!("longest: {}", longest(&string1, &string2));
+
+    println!("static: {}", static_lifetime());
+}
+
 fn main() {
     // Copying occurs when the value is simple and cheap to duplicate.
     // Ownership transfer occurs when the value is more complex and cannot be easily copied.
@@ -69,8 +131,9 @@ fn main() {
     println!("a = {}", a); // Error: borrow of moved value: `a`
 
     ownership_example();
-    ownership_transfer();
-    immutable_borrowing_example();
-    mutable_borrowing_example();
-    lifetimes_example();
+    ownership_transfer_func();
+    immutable_borrowing();
+    mutable_borrowing();
+    lifetimes();
+    advanced_lifetimes();
 }
\ No newline at end of file
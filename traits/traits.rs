@@ -51,7 +51,7 @@ impl Animal for Cat {
 // allowing us to call the trait methods on the `animal` parameter.
 fn print_animal_info<T: Animal>(animal: &T) {
     animal.make_sound();
-    println!("{} has {} legs.", animal.get_num_legs());
+    println!("This animal has {} legs.", animal.get_num_legs());
 }
 
 // Default Trait Methods